@@ -137,9 +137,44 @@ impl Clone for BigInt {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for BigInt {
+    fn zeroize(&mut self) {
+        // Overwrite every limb in place through `digits_mut`, rather than
+        // truncating or reallocating the buffer, and through a volatile
+        // write so the compiler can't elide it as a dead store to a value
+        // that's about to be dropped.
+        for digit in self.digits_mut() {
+            unsafe { core::ptr::write_volatile(digit, 0) };
+        }
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+        // Drop the wiped limbs from the length (keeping the allocation) so
+        // the value is a well-formed zero.
+        self.digits_mut().clear();
+        self.sign = NoSign;
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for BigUint {
+    fn zeroize(&mut self) {
+        // Overwrite the digit `Vec` in place through `digits_mut`, rather
+        // than truncating or reallocating it, and through a volatile write
+        // so the compiler can't elide it as a dead store to a value that's
+        // about to be dropped.
+        for digit in self.digits_mut() {
+            unsafe { core::ptr::write_volatile(digit, 0) };
+        }
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+        // Drop the wiped digits from the length (keeping the allocation) so
+        // the value is a well-formed zero.
+        self.digits_mut().clear();
+    }
+}
+
 #[cfg(feature = "quickcheck")]
 impl quickcheck::Arbitrary for BigInt {
-    fn arbitrary<G: quickcheck::Gen>(g: &mut G) -> Self {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
         let positive = bool::arbitrary(g);
         let sign = if positive { Sign::Plus } else { Sign::Minus };
         Self::from_biguint(sign, BigUint::arbitrary(g))
@@ -172,6 +207,74 @@ mod abitrary_impl {
     }
 }
 
+#[cfg(feature = "rand")]
+mod rand_impl {
+    use super::*;
+    use rand::Rng;
+
+    /// Sampling of random big integers, for use cases like keygen and
+    /// property-based testing that need values beyond the primitive
+    /// integer widths `Rng::gen` supports.
+    pub trait RandBigInt {
+        /// Generates a random `BigUint` with the given number of bits.
+        fn gen_biguint(&mut self, bit_size: u64) -> BigUint;
+
+        /// Generates a random `BigInt` with the given number of bits,
+        /// and a uniformly random sign (a zero magnitude is always
+        /// reported as [`NoSign`]).
+        fn gen_bigint(&mut self, bit_size: u64) -> BigInt;
+
+        /// Generates a uniformly random `BigUint` in the half-open range
+        /// `[lbound, ubound)`. Panics if `lbound >= ubound`.
+        fn gen_biguint_range(&mut self, lbound: &BigUint, ubound: &BigUint) -> BigUint;
+
+        /// Generates a uniformly random `BigInt` in the half-open range
+        /// `[lbound, ubound)`. Panics if `lbound >= ubound`.
+        fn gen_bigint_range(&mut self, lbound: &BigInt, ubound: &BigInt) -> BigInt;
+    }
+
+    impl<R: Rng + ?Sized> RandBigInt for R {
+        fn gen_biguint(&mut self, bit_size: u64) -> BigUint {
+            let limbs = ((bit_size + 31) / 32) as usize;
+            let mut digits: Vec<u32> = (0..limbs).map(|_| self.gen()).collect();
+            if let Some(top) = digits.last_mut() {
+                let used_bits = bit_size - (limbs as u64 - 1) * 32;
+                if used_bits < 32 {
+                    *top &= (1u32 << used_bits) - 1;
+                }
+            }
+            BigUint::new(digits)
+        }
+
+        fn gen_bigint(&mut self, bit_size: u64) -> BigInt {
+            let magnitude = self.gen_biguint(bit_size);
+            let sign = if self.gen() { Plus } else { Minus };
+            BigInt::from_biguint(sign, magnitude)
+        }
+
+        fn gen_biguint_range(&mut self, lbound: &BigUint, ubound: &BigUint) -> BigUint {
+            assert!(lbound < ubound, "lbound must be less than ubound");
+            let delta = ubound.clone() - lbound;
+            loop {
+                let candidate = self.gen_biguint(delta.bits());
+                if candidate < delta {
+                    return lbound.clone() + candidate;
+                }
+            }
+        }
+
+        fn gen_bigint_range(&mut self, lbound: &BigInt, ubound: &BigInt) -> BigInt {
+            assert!(lbound < ubound, "lbound must be less than ubound");
+            let delta = ubound.clone() - lbound;
+            let magnitude = self.gen_biguint_range(&BigUint::zero(), &delta.data);
+            lbound.clone() + BigInt::from(magnitude)
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+pub use self::rand_impl::RandBigInt;
+
 impl hash::Hash for BigInt {
     #[inline]
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
@@ -761,6 +864,10 @@ impl Num for BigInt {
     }
 }
 
+// Deferred: inline small-integer storage would change the vector type
+// `Shl`/`Shr` build when they forward to `BigUint`'s own shift impls
+// (`self.data << rhs`), but that storage lives in the `biguint` module,
+// which this crate snapshot doesn't carry -- tracked as blocked, not done.
 macro_rules! impl_shift {
     (@ref $Shx:ident :: $shx:ident, $ShxAssign:ident :: $shx_assign:ident, $rhs:ty) => {
         impl<'b> $Shx<&'b $rhs> for BigInt {
@@ -1005,6 +1112,53 @@ pow_impl!(usize);
 pow_impl!(u128);
 pow_impl!(BigUint);
 
+// `pow_impl!` can't cover a `BigInt` exponent: unlike the unsigned types
+// above, it needs a sign check before it can hand the magnitude off to
+// `BigUint::pow`, so it's spelled out by hand here instead.
+impl Pow<BigInt> for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn pow(self, rhs: BigInt) -> BigInt {
+        Pow::pow(self, &rhs)
+    }
+}
+
+impl<'b> Pow<&'b BigInt> for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn pow(self, rhs: &BigInt) -> BigInt {
+        assert!(
+            !rhs.is_negative(),
+            "Cannot raise BigInt to a negative power"
+        );
+        BigInt::from_biguint(powsign(self.sign, rhs), self.data.pow(&rhs.data))
+    }
+}
+
+impl<'a> Pow<BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn pow(self, rhs: BigInt) -> BigInt {
+        Pow::pow(self, &rhs)
+    }
+}
+
+impl<'a, 'b> Pow<&'b BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn pow(self, rhs: &BigInt) -> BigInt {
+        assert!(
+            !rhs.is_negative(),
+            "Cannot raise BigInt to a negative power"
+        );
+        BigInt::from_biguint(powsign(self.sign, rhs), Pow::pow(&self.data, &rhs.data))
+    }
+}
+
 trait UnsignedAbs {
     type Unsigned;
 
@@ -1052,8 +1206,23 @@ impl_unsigned_abs!(isize, usize);
 // We want to forward to BigUint::add, but it's not clear how that will go until
 // we compare both sign and magnitude.  So we duplicate this body for every
 // val/ref combination, deferring that decision to BigUint's own forwarding.
+//
+// Deferred: the digit-wise carry/borrow loop an `adc`/`sbb` fast path would
+// target lives inside `BigUint`'s own `Add`/`Sub` impls in the `biguint`
+// module, which this crate snapshot doesn't carry -- apart from its `i128`
+// fast path, this macro only decides sign and magnitude ordering before
+// forwarding to `$a_data + $b_data` / `$a_data - $b_data`, so it has no
+// digit loop of its own for `adc`/`sbb` to speed up;
+// tracked as blocked, not done.
 macro_rules! bigint_add {
-    ($a:expr, $a_owned:expr, $a_data:expr, $b:expr, $b_owned:expr, $b_data:expr) => {
+    ($a:expr, $a_owned:expr, $a_data:expr, $b:expr, $b_owned:expr, $b_data:expr) => {{
+        // Fast path: small operands that fit in an `i128` add directly in a
+        // machine register instead of going through the digit-vector path.
+        if let (Some(a128), Some(b128)) = ($a.to_i128(), $b.to_i128()) {
+            if let Some(sum) = a128.checked_add(b128) {
+                return BigInt::from(sum);
+            }
+        }
         match ($a.sign, $b.sign) {
             (_, NoSign) => $a_owned,
             (NoSign, _) => $b_owned,
@@ -1066,7 +1235,7 @@ macro_rules! bigint_add {
                 Equal => Zero::zero(),
             },
         }
-    };
+    }};
 }
 
 impl<'a, 'b> Add<&'b BigInt> for &'a BigInt {
@@ -1272,7 +1441,14 @@ impl AddAssign<i128> for BigInt {
 // we compare both sign and magnitude.  So we duplicate this body for every
 // val/ref combination, deferring that decision to BigUint's own forwarding.
 macro_rules! bigint_sub {
-    ($a:expr, $a_owned:expr, $a_data:expr, $b:expr, $b_owned:expr, $b_data:expr) => {
+    ($a:expr, $a_owned:expr, $a_data:expr, $b:expr, $b_owned:expr, $b_data:expr) => {{
+        // Fast path: small operands that fit in an `i128` subtract directly
+        // in a machine register instead of going through the digit-vector path.
+        if let (Some(a128), Some(b128)) = ($a.to_i128(), $b.to_i128()) {
+            if let Some(diff) = a128.checked_sub(b128) {
+                return BigInt::from(diff);
+            }
+        }
         match ($a.sign, $b.sign) {
             (_, NoSign) => $a_owned,
             (NoSign, _) => -$b_owned,
@@ -1285,7 +1461,7 @@ macro_rules! bigint_sub {
                 Equal => Zero::zero(),
             },
         }
-    };
+    }};
 }
 
 impl<'a, 'b> Sub<&'b BigInt> for &'a BigInt {
@@ -1558,6 +1734,13 @@ impl<'a, 'b> Mul<&'b BigInt> for &'a BigInt {
 
     #[inline]
     fn mul(self, other: &BigInt) -> BigInt {
+        // Fast path: small operands that fit in an `i128` multiply directly
+        // in a machine register instead of going through the digit-vector path.
+        if let (Some(a128), Some(b128)) = (self.to_i128(), other.to_i128()) {
+            if let Some(prod) = a128.checked_mul(b128) {
+                return BigInt::from(prod);
+            }
+        }
         BigInt::from_biguint(self.sign * other.sign, &self.data * &other.data)
     }
 }
@@ -2300,7 +2483,9 @@ impl Integer for BigInt {
     /// Greatest common divisor, least common multiple, and B??zout coefficients.
     #[inline]
     fn extended_gcd_lcm(&self, other: &BigInt) -> (num_integer::ExtendedGcd<BigInt>, BigInt) {
-        let egcd = self.extended_gcd(other);
+        // Use UFCS so this keeps calling `Integer::extended_gcd`'s struct-returning
+        // default impl, not the tuple-returning `BigInt::extended_gcd` inherent method.
+        let egcd = Integer::extended_gcd(self, other);
         let lcm = if egcd.gcd.is_zero() {
             BigInt::zero()
         } else {
@@ -2427,17 +2612,62 @@ impl ToPrimitive for BigInt {
 
     #[inline]
     fn to_f32(&self) -> Option<f32> {
-        let n = self.data.to_f32()?;
+        let n = biguint_round_to_even(&self.data, 24) as f32;
         Some(if self.sign == Minus { -n } else { n })
     }
 
     #[inline]
     fn to_f64(&self) -> Option<f64> {
-        let n = self.data.to_f64()?;
+        let n = biguint_round_to_even(&self.data, 53);
         Some(if self.sign == Minus { -n } else { n })
     }
 }
 
+/// Converts a `BigUint` magnitude to the nearest `f64`, rounding ties to
+/// even when the magnitude needs more precision than `mantissa_bits` (53
+/// for `f64`, 24 for `f32`) can hold. Magnitudes too large for a finite
+/// `f64` saturate to `f64::INFINITY`; narrowing the result to `f32`
+/// afterwards saturates the same way for magnitudes too large for `f32`.
+fn biguint_round_to_even(x: &BigUint, mantissa_bits: u64) -> f64 {
+    if x.is_zero() {
+        return 0.0;
+    }
+
+    let bits = x.bits();
+    if bits <= mantissa_bits {
+        let mut mantissa: u64 = 0;
+        for i in 0..bits {
+            if biguint_bit(x, i) {
+                mantissa |= 1 << i;
+            }
+        }
+        return mantissa as f64;
+    }
+
+    let mut shift = bits - mantissa_bits;
+    let mut mantissa: u64 = 0;
+    for i in 0..mantissa_bits {
+        if biguint_bit(x, shift + i) {
+            mantissa |= 1 << i;
+        }
+    }
+
+    // Round to nearest, ties to even: inspect the first discarded bit and
+    // OR all lower discarded bits together for the sticky bit.
+    let round_bit = biguint_bit(x, shift - 1);
+    let sticky = (0..shift - 1).any(|i| biguint_bit(x, i));
+    if round_bit && (sticky || mantissa & 1 == 1) {
+        mantissa += 1;
+        if mantissa == 1 << mantissa_bits {
+            // Rounding carried out of the mantissa; rescale by one bit.
+            mantissa >>= 1;
+            shift += 1;
+        }
+    }
+
+    (mantissa as f64) * 2f64.powi(shift as i32)
+}
+
 macro_rules! impl_try_from_bigint {
     ($T:ty, $to_ty:path) => {
         #[cfg(has_try_from)]
@@ -2497,6 +2727,11 @@ impl FromPrimitive for BigInt {
         Some(BigInt::from(n))
     }
 
+    #[inline]
+    fn from_f32(n: f32) -> Option<BigInt> {
+        FromPrimitive::from_f64(f64::from(n))
+    }
+
     #[inline]
     fn from_f64(n: f64) -> Option<BigInt> {
         if n >= 0.0 {
@@ -2508,6 +2743,56 @@ impl FromPrimitive for BigInt {
     }
 }
 
+/// The error returned by [`BigInt::to_f64_exact`] when the integer cannot be
+/// represented as an `f64` without loss of precision.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToF64ExactError {
+    rounded: f64,
+}
+
+impl ToF64ExactError {
+    /// The nearest representable `f64`, rounded to nearest, ties to even.
+    pub fn rounded(&self) -> f64 {
+        self.rounded
+    }
+}
+
+impl fmt::Display for ToF64ExactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "BigInt cannot be represented as f64 without loss of precision"
+        )
+    }
+}
+
+/// The error returned by [`BigInt::to_signed_bytes_be_padded`] and
+/// [`BigInt::to_signed_bytes_le_padded`] when the integer's minimal
+/// two's-complement encoding doesn't fit in the requested number of bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedBytesPaddingError {
+    minimal_len: usize,
+    requested_len: usize,
+}
+
+impl SignedBytesPaddingError {
+    /// The minimal number of bytes needed to represent the integer in two's
+    /// complement.
+    pub fn minimal_len(&self) -> usize {
+        self.minimal_len
+    }
+}
+
+impl fmt::Display for SignedBytesPaddingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "BigInt needs {} bytes of two's-complement encoding, but only {} were requested",
+            self.minimal_len, self.requested_len
+        )
+    }
+}
+
 impl From<i64> for BigInt {
     #[inline]
     fn from(n: i64) -> Self {
@@ -2612,6 +2897,10 @@ impl From<BigUint> for BigInt {
     }
 }
 
+// Deferred (same blocker as the smallvec-storage request above, no
+// further code landed): a SmallVec-backed magnitude needs
+// `IntDigits::digits_mut` to return `&mut SmallVec<[BigDigit; N]>`, a
+// `biguint` module change this crate snapshot doesn't carry.
 impl IntDigits for BigInt {
     #[inline]
     fn digits(&self) -> &[BigDigit] {
@@ -3035,6 +3324,72 @@ impl BigInt {
         bytes
     }
 
+    /// Returns the two's-complement byte representation of the `BigInt` in
+    /// big-endian byte order, padded (or sign-extended) to exactly `len`
+    /// bytes.
+    ///
+    /// Returns an error if the minimal two's-complement encoding of `self`
+    /// doesn't fit in `len` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::ToBigInt;
+    ///
+    /// let i = -1125.to_bigint().unwrap();
+    /// assert_eq!(i.to_signed_bytes_be_padded(4).unwrap(), vec![255, 255, 251, 155]);
+    /// assert!(i.to_signed_bytes_be_padded(1).is_err());
+    /// ```
+    pub fn to_signed_bytes_be_padded(&self, len: usize) -> Result<Vec<u8>, SignedBytesPaddingError> {
+        let minimal_len = self.to_signed_bytes_be().len();
+        if minimal_len > len {
+            return Err(SignedBytesPaddingError {
+                minimal_len,
+                requested_len: len,
+            });
+        }
+        let magnitude = self.data.to_bytes_be();
+        let mut bytes = vec![0u8; len];
+        bytes[len - magnitude.len()..].copy_from_slice(&magnitude);
+        if self.sign == Sign::Minus {
+            twos_complement_be(&mut bytes);
+        }
+        Ok(bytes)
+    }
+
+    /// Returns the two's-complement byte representation of the `BigInt` in
+    /// little-endian byte order, padded (or sign-extended) to exactly `len`
+    /// bytes.
+    ///
+    /// Returns an error if the minimal two's-complement encoding of `self`
+    /// doesn't fit in `len` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::ToBigInt;
+    ///
+    /// let i = -1125.to_bigint().unwrap();
+    /// assert_eq!(i.to_signed_bytes_le_padded(4).unwrap(), vec![155, 251, 255, 255]);
+    /// assert!(i.to_signed_bytes_le_padded(1).is_err());
+    /// ```
+    pub fn to_signed_bytes_le_padded(&self, len: usize) -> Result<Vec<u8>, SignedBytesPaddingError> {
+        let minimal_len = self.to_signed_bytes_le().len();
+        if minimal_len > len {
+            return Err(SignedBytesPaddingError {
+                minimal_len,
+                requested_len: len,
+            });
+        }
+        let magnitude = self.data.to_bytes_le();
+        let mut bytes = vec![0u8; len];
+        bytes[..magnitude.len()].copy_from_slice(&magnitude);
+        if self.sign == Sign::Minus {
+            twos_complement_le(&mut bytes);
+        }
+        Ok(bytes)
+    }
+
     /// Returns the integer formatted as a string in the given radix.
     /// `radix` must be in the range `2...36`.
     ///
@@ -3155,6 +3510,36 @@ impl BigInt {
         self.data.bits()
     }
 
+    /// Converts this `BigInt` to an `f64`, returning an error with the
+    /// nearest rounded value if it cannot be represented exactly.
+    ///
+    /// An integer is exactly representable as an `f64` when its significant
+    /// bits (the magnitude's bit length minus its trailing zero bits) fit in
+    /// the 53 bits of an `f64` mantissa *and* its magnitude falls within
+    /// `f64`'s finite exponent range -- `to_f64` silently saturates
+    /// oversized magnitudes to `+/-infinity` rather than returning `None`,
+    /// so bit count alone isn't enough to call a value exact.
+    pub fn to_f64_exact(&self) -> Result<f64, ToF64ExactError> {
+        let rounded = match self.to_f64() {
+            Some(n) => n,
+            None => {
+                return Err(ToF64ExactError {
+                    rounded: if self.is_negative() {
+                        f64::NEG_INFINITY
+                    } else {
+                        f64::INFINITY
+                    },
+                })
+            }
+        };
+        let significant_bits = self.bits() - self.trailing_zeros().unwrap_or(0);
+        if significant_bits <= 53 && rounded.is_finite() {
+            Ok(rounded)
+        } else {
+            Err(ToF64ExactError { rounded })
+        }
+    }
+
     /// Converts this `BigInt` into a `BigUint`, if it's not negative.
     #[inline]
     pub fn to_biguint(&self) -> Option<BigUint> {
@@ -3200,18 +3585,32 @@ impl BigInt {
     /// The result will be in the interval `[0, modulus)` for `modulus > 0`,
     /// or in the interval `(modulus, 0]` for `modulus < 0`
     ///
-    /// Panics if the exponent is negative or the modulus is zero.
+    /// A negative `exponent` is supported, and computes the modular
+    /// exponentiation of the modular inverse of `self`; this panics if
+    /// `self` has no inverse modulo `modulus`.
+    ///
+    /// Panics if the modulus is zero.
     pub fn modpow(&self, exponent: &Self, modulus: &Self) -> Self {
-        assert!(
-            !exponent.is_negative(),
-            "negative exponentiation is not supported!"
-        );
         assert!(
             !modulus.is_zero(),
             "attempt to calculate with zero modulus!"
         );
 
-        let result = self.data.modpow(&exponent.data, &modulus.data);
+        if exponent.is_negative() {
+            let inverse = self.mod_inverse(modulus).unwrap_or_else(|| {
+                panic!("self has no inverse modulo modulus, so negative exponentiation is undefined")
+            });
+            return inverse.modpow(&-exponent, modulus);
+        }
+
+        // Odd moduli are the common case for the RSA/DH-style workloads this
+        // method targets, and a Montgomery reduction avoids the per-multiply
+        // trial division that `BigUint::modpow`'s general path pays for.
+        let result = if modulus.data.is_odd() {
+            modpow_montgomery(&self.data, &exponent.data, &modulus.data)
+        } else {
+            self.data.modpow(&exponent.data, &modulus.data)
+        };
         if result.is_zero() {
             return BigInt::zero();
         }
@@ -3229,6 +3628,88 @@ impl BigInt {
         BigInt::from_biguint(sign, mag)
     }
 
+    /// Returns `(self ^ exponent) mod modulus`, computed with a Montgomery
+    /// ladder so each exponent bit costs exactly one squaring and one
+    /// multiply regardless of its value, for callers exponentiating secret
+    /// data (e.g. an RSA private exponent) where [`modpow`](BigInt::modpow)'s
+    /// data-dependent squaring schedule would leak timing.
+    ///
+    /// Only `exponent` is treated as secret: the bit-lengths of `self` and
+    /// `modulus` are still observable, and `self` is reduced mod `modulus`
+    /// up front with a non-constant-time `%`.
+    ///
+    /// Requires a positive odd `modulus` and a non-negative `exponent`.
+    pub fn modpow_secure(&self, exponent: &Self, modulus: &Self) -> Self {
+        assert!(
+            modulus.is_positive() && modulus.data.is_odd(),
+            "modpow_secure requires a positive odd modulus"
+        );
+        assert!(
+            !exponent.is_negative(),
+            "modpow_secure does not support negative exponents"
+        );
+
+        let result = modpow_montgomery_ladder(&self.data, &exponent.data, &modulus.data);
+        if result.is_zero() {
+            return BigInt::zero();
+        }
+        let mag = if self.is_negative() && exponent.is_odd() {
+            &modulus.data - result
+        } else {
+            result
+        };
+        BigInt::from_biguint(Plus, mag)
+    }
+
+    /// Returns `(self ^ exponent) mod modulus`, or `None` if the modulus is
+    /// zero or if `exponent` is negative and `self` has no inverse modulo
+    /// `modulus` -- the non-panicking counterpart to [`modpow`](BigInt::modpow).
+    pub fn checked_modpow(&self, exponent: &Self, modulus: &Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        if exponent.is_negative() {
+            let inverse = self.mod_inverse(modulus)?;
+            return inverse.checked_modpow(&-exponent, modulus);
+        }
+        Some(self.modpow(exponent, modulus))
+    }
+
+    /// Returns `(gcd, x, y)` such that `self * x + other * y == gcd`, using
+    /// the extended Euclidean algorithm.
+    ///
+    /// This is a convenience wrapper around
+    /// [`Integer::extended_gcd`](num_integer::Integer::extended_gcd) that
+    /// unpacks its `ExtendedGcd` into the Bezout coefficients directly.
+    pub fn extended_gcd(&self, other: &Self) -> (BigInt, BigInt, BigInt) {
+        let egcd = Integer::extended_gcd(self, other);
+        (egcd.gcd, egcd.x, egcd.y)
+    }
+
+    /// Returns the modular multiplicative inverse of `self` modulo `modulus`,
+    /// or `None` if `self` and `modulus` are not coprime.
+    ///
+    /// The result, when it exists, rounds like `mod_floor`: it is in
+    /// `[0, modulus)` for `modulus > 0`, or in `(modulus, 0]` for
+    /// `modulus < 0`.
+    pub fn mod_inverse(&self, modulus: &Self) -> Option<BigInt> {
+        if modulus.is_zero() {
+            return None;
+        }
+        let (g, x, _) = self.extended_gcd(modulus);
+        if !g.is_one() {
+            return None;
+        }
+        Some(x.mod_floor(modulus))
+    }
+
+    /// Alias for [`mod_inverse`](BigInt::mod_inverse), matching the name
+    /// used by other modular-arithmetic libraries.
+    #[inline]
+    pub fn modinv(&self, modulus: &Self) -> Option<BigInt> {
+        self.mod_inverse(modulus)
+    }
+
     /// Returns the truncated principal square root of `self` --
     /// see [Roots::sqrt](https://docs.rs/num-integer/0.1/num_integer/trait.Roots.html#method.sqrt).
     pub fn sqrt(&self) -> Self {
@@ -3247,11 +3728,158 @@ impl BigInt {
         Roots::nth_root(self, n)
     }
 
+    /// Calculates the quotient of Euclidean division of `self` by `other`.
+    ///
+    /// This computes the integer `q` such that `self = q * other + r`, with
+    /// `r = self.rem_euclid(other)` and `0 <= r < other.abs()`.
+    ///
+    /// In other words, the result is `self / other` rounded to the integer
+    /// `q` such that `self >= q * other` (for either sign of `other`),
+    /// matching the behavior of the primitive integer `div_euclid` methods.
+    ///
+    /// Panics if `other` is zero.
+    pub fn div_euclid(&self, other: &Self) -> Self {
+        let (q, r) = self.div_rem(other);
+        if r.is_negative() {
+            if other.is_positive() {
+                q - 1u32
+            } else {
+                q + 1u32
+            }
+        } else {
+            q
+        }
+    }
+
+    /// Calculates the non-negative remainder of Euclidean division of `self`
+    /// by `other`.
+    ///
+    /// The result satisfies `0 <= self.rem_euclid(other) < other.abs()`,
+    /// matching the behavior of the primitive integer `rem_euclid` methods.
+    ///
+    /// Panics if `other` is zero.
+    pub fn rem_euclid(&self, other: &Self) -> Self {
+        let r = self % other;
+        if r.is_negative() {
+            if other.is_positive() {
+                r + other
+            } else {
+                r - other
+            }
+        } else {
+            r
+        }
+    }
+
+    /// Calculates both the Euclidean quotient and remainder of `self` by
+    /// `other`, returned as `(quotient, remainder)`.
+    ///
+    /// See [`div_euclid`](BigInt::div_euclid) and
+    /// [`rem_euclid`](BigInt::rem_euclid).
+    ///
+    /// Panics if `other` is zero.
+    pub fn div_rem_euclid(&self, other: &Self) -> (Self, Self) {
+        let (q, r) = self.div_rem(other);
+        if r.is_negative() {
+            if other.is_positive() {
+                (q - 1u32, r + other)
+            } else {
+                (q + 1u32, r - other)
+            }
+        } else {
+            (q, r)
+        }
+    }
+
+    /// Raises `self` to the power of `exponent`, returning `None` if
+    /// `exponent` is negative, since the result would not be an integer.
+    pub fn checked_pow(&self, exponent: &Self) -> Option<Self> {
+        if exponent.is_negative() {
+            None
+        } else {
+            Some(Pow::pow(self, exponent))
+        }
+    }
+
     /// Returns the number of least-significant bits that are zero,
     /// or `None` if the entire number is zero.
     pub fn trailing_zeros(&self) -> Option<u64> {
         self.data.trailing_zeros()
     }
+
+    /// Returns the truth value of the bit at position `bit`, under the same
+    /// infinite two's-complement model assumed by the bitwise operators:
+    /// non-negative values read straight from the magnitude, while negative
+    /// values read the logical negation of `self.magnitude() - 1`, with all
+    /// bits above its length implicitly set.
+    #[inline]
+    pub fn bit(&self, bit: u64) -> bool {
+        if self.is_negative() {
+            let mut m = self.data.clone();
+            m -= 1u32;
+            !biguint_bit(&m, bit)
+        } else {
+            biguint_bit(&self.data, bit)
+        }
+    }
+
+    /// Sets or clears the bit at position `bit`, under the same infinite
+    /// two's-complement model as [`bit`](BigInt::bit), extending the digit
+    /// vector with the correct sign extension if `bit` falls beyond the
+    /// current length. The result is never a negative zero.
+    pub fn set_bit(&mut self, bit: u64, value: bool) {
+        if self.is_negative() {
+            // self == -(m + 1), so the infinite two's-complement bits of
+            // self are the logical negation of the bits of m.
+            let mut m = self.data.clone();
+            m -= 1u32;
+            set_biguint_bit(&mut m, bit, !value);
+            self.data = m;
+            self.data += 1u32;
+            // data is m + 1 >= 1, so it can never become zero.
+            self.sign = Minus;
+        } else {
+            set_biguint_bit(&mut self.data, bit, value);
+            self.sign = if self.data.is_zero() { NoSign } else { Plus };
+        }
+    }
+}
+
+/// Reads bit `bit` of a `BigUint`'s ordinary (non-two's-complement) bit
+/// pattern, treating bits beyond the digit vector as zero.
+#[inline]
+fn biguint_bit(x: &BigUint, bit: u64) -> bool {
+    let bits_per_digit = u64::from(big_digit::BITS);
+    let digit_index = (bit / bits_per_digit) as usize;
+    let digit = match x.digits().get(digit_index) {
+        Some(&digit) => digit,
+        None => return false,
+    };
+    let bit_index = (bit % bits_per_digit) as u32;
+    digit & (1 << bit_index) != 0
+}
+
+/// Sets or clears bit `bit` of a `BigUint`'s ordinary bit pattern, extending
+/// the digit vector with zeros if necessary.
+fn set_biguint_bit(x: &mut BigUint, bit: u64, value: bool) {
+    let bits_per_digit = u64::from(big_digit::BITS);
+    let digit_index = (bit / bits_per_digit) as usize;
+    let bit_index = (bit % bits_per_digit) as u32;
+
+    let digits = x.digits_mut();
+    if digit_index >= digits.len() {
+        if !value {
+            // Bits beyond the current length are already zero.
+            return;
+        }
+        digits.resize(digit_index + 1, 0);
+    }
+    if value {
+        digits[digit_index] |= 1 << bit_index;
+    } else {
+        digits[digit_index] &= !(1 << bit_index);
+    }
+    x.normalize();
 }
 
 impl_sum_iter_type!(BigInt);
@@ -3288,6 +3916,134 @@ where
     }
 }
 
+/// A Montgomery reduction context for an odd modulus, used internally by
+/// `BigInt::modpow` to avoid repeated trial division in its square-and-multiply
+/// loop. `R` is taken to be the smallest power of two, aligned to a 32-bit
+/// boundary, that exceeds the modulus.
+struct MontgomeryCtx {
+    modulus: BigUint,
+    r_bits: u64,
+    r_mask: BigUint,
+    n_prime: BigUint,
+}
+
+impl MontgomeryCtx {
+    fn new(modulus: &BigUint) -> Self {
+        debug_assert!(modulus.is_odd(), "Montgomery reduction requires an odd modulus");
+        let r_bits = ((modulus.bits() + 31) / 32) * 32;
+        let r = BigUint::one() << r_bits;
+        let r_mask = &r - 1u32;
+        // modulus is odd and r is a power of two, so they are always coprime.
+        let m_inv = BigInt::from(modulus.clone())
+            .mod_inverse(&BigInt::from(r.clone()))
+            .expect("odd modulus is coprime to a power of two")
+            .to_biguint()
+            .expect("mod_inverse of positive operands is non-negative");
+        let n_prime = &r - m_inv;
+        MontgomeryCtx {
+            modulus: modulus.clone(),
+            r_bits,
+            r_mask,
+            n_prime,
+        }
+    }
+
+    /// Converts `a` (already reduced mod `modulus`) into Montgomery form.
+    fn to_mont(&self, a: &BigUint) -> BigUint {
+        (a << self.r_bits) % &self.modulus
+    }
+
+    /// Montgomery reduction: maps `t < modulus * R` back down to `[0, modulus)`.
+    fn redc(&self, t: BigUint) -> BigUint {
+        let q = (&t * &self.n_prime) & &self.r_mask;
+        let reduced = (t + q * &self.modulus) >> self.r_bits;
+        if reduced >= self.modulus {
+            reduced - &self.modulus
+        } else {
+            reduced
+        }
+    }
+
+    /// Multiplies two values that are already in Montgomery form.
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        self.redc(a * b)
+    }
+}
+
+/// Computes `base.pow(exponent) % modulus` by right-to-left
+/// square-and-multiply in Montgomery form. `modulus` must be odd; `modulus == 1` is handled as a fast path.
+fn modpow_montgomery(base: &BigUint, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+    if modulus.is_one() {
+        return BigUint::zero();
+    }
+
+    let ctx = MontgomeryCtx::new(modulus);
+    let base = base % modulus;
+
+    let mut result = ctx.to_mont(&BigUint::one());
+    let mut b = ctx.to_mont(&base);
+    for i in 0..exponent.bits() {
+        if biguint_bit(exponent, i) {
+            result = ctx.mul(&result, &b);
+        }
+        b = ctx.mul(&b, &b);
+    }
+    ctx.redc(result)
+}
+
+/// Conditionally swaps the magnitudes of `a` and `b` without branching on
+/// `cond`, so the instruction and memory-access pattern is the same whether
+/// or not the swap happens. Only `cond` is treated as secret here -- the
+/// lengths of `a` and `b` are still observable, since padding them to a
+/// common length is itself a data-dependent allocation.
+fn cswap(cond: bool, a: &mut BigUint, b: &mut BigUint) {
+    let len = a.digits().len().max(b.digits().len());
+    while a.digits().len() < len {
+        a.digits_mut().push(0);
+    }
+    while b.digits().len() < len {
+        b.digits_mut().push(0);
+    }
+
+    let mask: BigDigit = (0 as BigDigit).wrapping_sub(cond as BigDigit);
+    for i in 0..len {
+        let (ad, bd) = (a.digits()[i], b.digits()[i]);
+        let tmp = mask & (ad ^ bd);
+        a.digits_mut()[i] = ad ^ tmp;
+        b.digits_mut()[i] = bd ^ tmp;
+    }
+    a.normalize();
+    b.normalize();
+}
+
+/// Computes `base.pow(exponent) % modulus` with a Montgomery ladder where
+/// every exponent bit performs exactly one squaring and one multiply, using
+/// [`cswap`] instead of a data-dependent branch to select which accumulator
+/// gets updated. `modulus` must be odd; `modulus == 1` is handled as a fast
+/// path. Only `exponent` is treated as secret -- the bit-lengths of `base`
+/// and `modulus` are not hidden.
+fn modpow_montgomery_ladder(base: &BigUint, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+    if modulus.is_one() {
+        return BigUint::zero();
+    }
+
+    let ctx = MontgomeryCtx::new(modulus);
+    let base = base % modulus;
+
+    let mut r0 = ctx.to_mont(&BigUint::one());
+    let mut r1 = ctx.to_mont(&base);
+
+    let bits = exponent.bits();
+    for i in (0..bits).rev() {
+        let bit = biguint_bit(exponent, i);
+        cswap(bit, &mut r0, &mut r1);
+        r1 = ctx.mul(&r0, &r1);
+        r0 = ctx.mul(&r0, &r0);
+        cswap(bit, &mut r0, &mut r1);
+    }
+    ctx.redc(r0)
+}
+
 #[test]
 fn test_from_biguint() {
     fn check(inp_s: Sign, inp_n: usize, ans_s: Sign, ans_n: usize) {
@@ -3336,3 +4092,435 @@ fn test_assign_from_slice() {
     check(Minus, 1, Minus, 1);
     check(NoSign, 1, NoSign, 0);
 }
+
+#[test]
+fn test_bit() {
+    assert_eq!(BigInt::from(0).bit(0), false);
+    assert_eq!(BigInt::from(1).bit(0), true);
+    assert_eq!(BigInt::from(1).bit(1), false);
+    assert_eq!(BigInt::from(-1).bit(0), true);
+    assert_eq!(BigInt::from(-1).bit(100), true);
+    assert_eq!(BigInt::from(-2).bit(0), false);
+    assert_eq!(BigInt::from(-2).bit(1), true);
+    assert_eq!(BigInt::from(4).bit(2), true);
+    assert_eq!(BigInt::from(4).bit(3), false);
+}
+
+#[test]
+fn test_set_bit() {
+    let mut n = BigInt::from(0);
+    n.set_bit(0, true);
+    assert_eq!(n, BigInt::from(1));
+
+    let mut n = BigInt::from(5); // 0b101
+    n.set_bit(1, true);
+    assert_eq!(n, BigInt::from(7));
+    n.set_bit(0, false);
+    assert_eq!(n, BigInt::from(6));
+
+    let mut n = BigInt::from(-1);
+    n.set_bit(0, false);
+    assert_eq!(n, BigInt::from(-2));
+
+    let mut n = BigInt::from(-1);
+    n.set_bit(3, false);
+    assert_eq!(n, BigInt::from(-9));
+
+    let mut n = BigInt::from(-9);
+    n.set_bit(3, true);
+    assert_eq!(n, BigInt::from(-1));
+}
+
+#[test]
+fn test_signed_bytes_roundtrip() {
+    fn check(n: i64, be: &[u8]) {
+        let big = BigInt::from(n);
+        assert_eq!(big.to_signed_bytes_be(), be);
+        assert_eq!(BigInt::from_signed_bytes_be(be), big);
+
+        let mut le = be.to_vec();
+        le.reverse();
+        assert_eq!(big.to_signed_bytes_le(), le);
+        assert_eq!(BigInt::from_signed_bytes_le(&le), big);
+    }
+
+    check(0, &[0]);
+    check(-1, &[0xff]);
+    check(127, &[0x7f]);
+    check(128, &[0x80, 0x00]);
+    check(-128, &[0x80]);
+    check(-129, &[0xff, 0x7f]);
+}
+
+#[test]
+fn test_i128_fast_path_arithmetic() {
+    let a = BigInt::from(12345_i64);
+    let b = BigInt::from(-6789_i64);
+    assert_eq!(&a + &b, BigInt::from(12345_i64 - 6789_i64));
+    assert_eq!(&a - &b, BigInt::from(12345_i64 + 6789_i64));
+    assert_eq!(&a * &b, BigInt::from(12345_i64 * -6789_i64));
+
+    // Falls back to the digit-vector path once a result overflows `i128`.
+    let huge = BigInt::from(i128::MAX) + BigInt::from(i128::MAX);
+    assert_eq!(huge, BigInt::from(i128::MAX) * 2);
+}
+
+#[test]
+fn test_extended_gcd() {
+    let a = BigInt::from(240);
+    let b = BigInt::from(46);
+    let (g, x, y) = a.extended_gcd(&b);
+    assert_eq!(g, BigInt::from(2));
+    assert_eq!(&a * &x + &b * &y, g);
+}
+
+#[test]
+fn test_mod_inverse() {
+    let a = BigInt::from(3);
+    let m = BigInt::from(11);
+    let inv = a.mod_inverse(&m).unwrap();
+    assert_eq!((&a * &inv).mod_floor(&m), BigInt::one());
+
+    // 2 and 4 share a factor of 2 with modulus 4, so no inverse exists.
+    assert_eq!(BigInt::from(2).mod_inverse(&BigInt::from(4)), None);
+
+    assert_eq!(BigInt::from(5).mod_inverse(&BigInt::one()), Some(BigInt::zero()));
+}
+
+#[test]
+fn test_roots() {
+    assert_eq!(BigInt::from(0).sqrt(), BigInt::from(0));
+    assert_eq!(BigInt::from(1).sqrt(), BigInt::from(1));
+    assert_eq!(BigInt::from(99).sqrt(), BigInt::from(9));
+    assert_eq!(BigInt::from(100).sqrt(), BigInt::from(10));
+
+    assert_eq!(BigInt::from(-8).cbrt(), BigInt::from(-2));
+    assert_eq!(BigInt::from(27).cbrt(), BigInt::from(3));
+    assert_eq!(BigInt::from(26).cbrt(), BigInt::from(2));
+
+    assert_eq!(BigInt::from(-32).nth_root(5), BigInt::from(-2));
+    assert_eq!(BigInt::from(81).nth_root(4), BigInt::from(3));
+}
+
+#[test]
+#[should_panic(expected = "imaginary")]
+fn test_sqrt_negative_panics() {
+    let _ = BigInt::from(-4).sqrt();
+}
+
+#[cfg(feature = "quickcheck")]
+#[test]
+fn test_arbitrary_invariants() {
+    use quickcheck::Arbitrary;
+
+    let mut gen = quickcheck::Gen::new(32);
+    for _ in 0..100 {
+        let n = BigInt::arbitrary(&mut gen);
+        // The generator must never produce a negative zero.
+        assert_eq!(n.sign() == Sign::NoSign, n.is_zero());
+        for shrunk in n.shrink().take(10) {
+            assert_eq!(shrunk.sign() == Sign::NoSign, shrunk.is_zero());
+        }
+    }
+}
+
+#[test]
+fn test_modpow_montgomery_path() {
+    // modulus is odd, so this exercises the Montgomery fast path.
+    let base = BigInt::from(4);
+    let exp = BigInt::from(13);
+    let modulus = BigInt::from(497);
+    assert_eq!(base.modpow(&exp, &modulus), BigInt::from(445));
+
+    // modulus == 1 is a fast path regardless of base/exponent.
+    assert_eq!(
+        BigInt::from(123).modpow(&BigInt::from(456), &BigInt::one()),
+        BigInt::zero()
+    );
+
+    // 561 is an odd Carmichael number coprime to 7, so the Montgomery path
+    // must give 7^560 == 1 (mod 561).
+    let base = BigInt::from(7);
+    let exp = BigInt::from(560);
+    assert_eq!(
+        base.modpow(&exp, &BigInt::from(561)),
+        BigInt::from(1)
+    );
+}
+
+#[test]
+fn test_modpow_negative_exponent() {
+    let base = BigInt::from(3);
+    let modulus = BigInt::from(11);
+    let inverse = base.mod_inverse(&modulus).unwrap();
+
+    assert_eq!(
+        base.modpow(&BigInt::from(-1), &modulus),
+        inverse
+    );
+    assert_eq!(
+        base.modpow(&BigInt::from(-5), &modulus),
+        inverse.modpow(&BigInt::from(5), &modulus)
+    );
+}
+
+#[test]
+#[should_panic(expected = "no inverse")]
+fn test_modpow_negative_exponent_no_inverse_panics() {
+    // 2 and 4 share a factor of 2, so 2 has no inverse modulo 4.
+    let _ = BigInt::from(2).modpow(&BigInt::from(-1), &BigInt::from(4));
+}
+
+#[test]
+fn test_div_euclid_rem_euclid() {
+    let cases = [
+        (8, 3, 2, 2),
+        (8, -3, -2, 2),
+        (-8, 3, -3, 1),
+        (-8, -3, 3, 1),
+        (1, 2, 0, 1),
+        (-1, 2, -1, 1),
+    ];
+    for &(a, b, q, r) in cases.iter() {
+        let a = BigInt::from(a);
+        let b = BigInt::from(b);
+        assert_eq!(a.div_euclid(&b), BigInt::from(q));
+        assert_eq!(a.rem_euclid(&b), BigInt::from(r));
+        assert_eq!(
+            a.div_rem_euclid(&b),
+            (BigInt::from(q), BigInt::from(r))
+        );
+        assert_eq!(&a.div_euclid(&b) * &b + a.rem_euclid(&b), a);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize() {
+    use zeroize::Zeroize;
+
+    let mut n = BigInt::from(123456789);
+    n.zeroize();
+    assert_eq!(n, BigInt::zero());
+    assert_eq!(n.sign(), Sign::NoSign);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize_biguint() {
+    use zeroize::Zeroize;
+
+    let mut n = BigUint::from(123456789u32);
+    n.zeroize();
+    assert_eq!(n, BigUint::zero());
+}
+
+#[test]
+fn test_to_f64_exact() {
+    assert_eq!(BigInt::zero().to_f64_exact(), Ok(0.0));
+    assert_eq!(BigInt::from(-1234).to_f64_exact(), Ok(-1234.0));
+
+    // 2^53 is exactly representable...
+    let p53 = BigInt::one() << 53;
+    assert_eq!(p53.to_f64_exact(), Ok(9007199254740992.0));
+
+    // ...and so is a large power of two with many trailing zero bits...
+    let p200 = BigInt::one() << 200;
+    assert!(p200.to_f64_exact().is_ok());
+
+    // ...but 2^53 + 1 needs 54 significant bits with no trailing zeros to
+    // spare, so it can't be represented exactly.
+    let not_exact = (BigInt::one() << 53) + 1;
+    let err = not_exact.to_f64_exact().unwrap_err();
+    assert_eq!(err.rounded(), 9007199254740992.0);
+
+    // 2^2000 has a single significant bit, but its magnitude is far beyond
+    // f64::MAX, so `to_f64` saturates it to infinity; that must not be
+    // reported as exact.
+    let p2000 = BigInt::one() << 2000;
+    let err = p2000.to_f64_exact().unwrap_err();
+    assert_eq!(err.rounded(), f64::INFINITY);
+}
+
+#[test]
+fn test_from_f32() {
+    assert_eq!(BigInt::from_f32(1234.0), Some(BigInt::from(1234)));
+    assert_eq!(BigInt::from_f32(-1234.0), Some(BigInt::from(-1234)));
+    assert_eq!(BigInt::from_f32(f32::NAN), None);
+}
+
+#[test]
+fn test_to_f64_round_to_even() {
+    // 2^53 + 1 sits exactly halfway between two f64-representable values;
+    // round-to-even must round down since 2^53 has an even mantissa.
+    let halfway_down = (BigInt::one() << 53) + 1;
+    assert_eq!(halfway_down.to_f64(), Some(9007199254740992.0));
+
+    // 2^53 + 3 is also halfway between 2^53 + 2 and 2^53 + 4, but 2^53 + 4
+    // has an even mantissa, so this one rounds up.
+    let halfway_up = (BigInt::one() << 53) + 3;
+    assert_eq!(halfway_up.to_f64(), Some(9007199254740996.0));
+
+    // Sign is reapplied after rounding the magnitude.
+    assert_eq!((-&halfway_up).to_f64(), Some(-9007199254740996.0));
+
+    // A magnitude far beyond f64::MAX saturates to infinity rather than
+    // panicking or wrapping.
+    let huge = BigInt::one() << 2000;
+    assert_eq!(huge.to_f64(), Some(f64::INFINITY));
+    assert_eq!((-huge).to_f64(), Some(f64::NEG_INFINITY));
+}
+
+#[test]
+fn test_to_f32_round_to_even() {
+    // 2^24 + 1 is the smallest integer that isn't exactly representable as
+    // an f32; it's halfway between 2^24 and 2^24 + 2, and 2^24 has an even
+    // mantissa, so it rounds down.
+    let halfway_down = (BigInt::one() << 24) + 1;
+    assert_eq!(halfway_down.to_f32(), Some(16777216.0f32));
+
+    let huge = BigInt::one() << 200;
+    assert_eq!(huge.to_f32(), Some(f32::INFINITY));
+}
+
+#[test]
+fn test_pow_bigint_exponent() {
+    let base = BigInt::from(-2);
+    assert_eq!(Pow::pow(base.clone(), BigInt::from(10)), BigInt::from(1024));
+    assert_eq!(Pow::pow(base.clone(), BigInt::from(9)), BigInt::from(-512));
+    assert_eq!(Pow::pow(&base, &BigInt::from(3)), BigInt::from(-8));
+
+    assert_eq!(base.checked_pow(&BigInt::from(4)), Some(BigInt::from(16)));
+    assert_eq!(base.checked_pow(&BigInt::from(-1)), None);
+}
+
+#[test]
+#[should_panic(expected = "negative power")]
+fn test_pow_bigint_negative_exponent_panics() {
+    let _ = Pow::pow(BigInt::from(2), BigInt::from(-1));
+}
+
+#[test]
+fn test_modinv_alias_and_zero_modulus() {
+    let a = BigInt::from(3);
+    let m = BigInt::from(11);
+    assert_eq!(a.modinv(&m), a.mod_inverse(&m));
+
+    // A zero modulus has no inverse and must not panic.
+    assert_eq!(BigInt::from(1).mod_inverse(&BigInt::zero()), None);
+    assert_eq!(BigInt::from(1).modinv(&BigInt::zero()), None);
+}
+
+#[test]
+fn test_checked_modpow() {
+    assert_eq!(
+        BigInt::from(4).checked_modpow(&BigInt::from(13), &BigInt::from(497)),
+        Some(BigInt::from(445))
+    );
+    assert_eq!(
+        BigInt::from(4).checked_modpow(&BigInt::from(13), &BigInt::zero()),
+        None
+    );
+    // 2 has no inverse modulo 4.
+    assert_eq!(
+        BigInt::from(2).checked_modpow(&BigInt::from(-1), &BigInt::from(4)),
+        None
+    );
+}
+
+#[test]
+fn test_modpow_secure() {
+    assert_eq!(
+        BigInt::from(4).modpow_secure(&BigInt::from(13), &BigInt::from(497)),
+        BigInt::from(445)
+    );
+    assert_eq!(
+        BigInt::from(7).modpow_secure(&BigInt::from(560), &BigInt::from(561)),
+        BigInt::from(1)
+    );
+
+    // Must agree with the variable-time `modpow` across a range of inputs.
+    for base in -20..20 {
+        for exp in 0..10 {
+            let base = BigInt::from(base);
+            let exp = BigInt::from(exp);
+            let modulus = BigInt::from(97);
+            assert_eq!(
+                base.modpow_secure(&exp, &modulus),
+                base.modpow(&exp, &modulus)
+            );
+        }
+    }
+
+    // Exponent wider than the modulus must not be truncated.
+    assert_eq!(
+        BigInt::from(4).modpow_secure(&BigInt::from(256), &BigInt::from(97)),
+        BigInt::from(35)
+    );
+}
+
+#[test]
+#[should_panic(expected = "odd modulus")]
+fn test_modpow_secure_even_modulus_panics() {
+    let _ = BigInt::from(4).modpow_secure(&BigInt::from(13), &BigInt::from(496));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_rand_bigint() {
+    use rand::SeedableRng;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+
+    for _ in 0..20 {
+        let n = rng.gen_biguint(37);
+        assert!(n.bits() <= 37);
+    }
+
+    for _ in 0..20 {
+        let n: BigInt = rng.gen_bigint(64);
+        assert!(n.bits() <= 64);
+    }
+
+    let lbound = BigInt::from(-1000);
+    let ubound = BigInt::from(1000);
+    for _ in 0..200 {
+        let n = rng.gen_bigint_range(&lbound, &ubound);
+        assert!(n >= lbound && n < ubound);
+    }
+}
+
+#[test]
+fn test_to_signed_bytes_padded() {
+    let neg = BigInt::from(-1125);
+    assert_eq!(
+        neg.to_signed_bytes_be_padded(4).unwrap(),
+        vec![255, 255, 251, 155]
+    );
+    assert_eq!(
+        neg.to_signed_bytes_le_padded(4).unwrap(),
+        vec![155, 251, 255, 255]
+    );
+
+    let pos = BigInt::from(1125);
+    assert_eq!(
+        pos.to_signed_bytes_be_padded(4).unwrap(),
+        vec![0, 0, 4, 101]
+    );
+    assert_eq!(
+        pos.to_signed_bytes_le_padded(4).unwrap(),
+        vec![101, 4, 0, 0]
+    );
+
+    // Padding to the minimal length is a no-op.
+    assert_eq!(neg.to_signed_bytes_be_padded(2).unwrap(), neg.to_signed_bytes_be());
+    assert_eq!(neg.to_signed_bytes_le_padded(2).unwrap(), neg.to_signed_bytes_le());
+
+    // Too narrow to hold the minimal encoding.
+    let err = neg.to_signed_bytes_be_padded(1).unwrap_err();
+    assert_eq!(err.minimal_len(), 2);
+    assert!(neg.to_signed_bytes_le_padded(1).is_err());
+
+    // -1 in two's complement is all-ones at any width.
+    assert_eq!(BigInt::from(-1).to_signed_bytes_be_padded(4).unwrap(), vec![255; 4]);
+}